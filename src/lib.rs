@@ -22,6 +22,9 @@ use trait_set::trait_set;
 use histogram_macros::histogram_struct;
 histogram_struct!{BTreeHistogram, BTreeHistKey, BTreeMap, BTreeSet, Iter, Ord}
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 trait_set! {
     pub trait LabelType = KeyType + Ord;
     pub trait FeatureType = Hash + Clone + Eq + PartialEq;
@@ -34,23 +37,270 @@ pub trait NaiveBayesExtractor {
     fn extract(&self, value: &Self::InputValue) -> Vec<Self::Feature>;
 }
 
-pub struct NaiveBayes<L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Feature=F>> {
+/// Companion to [`NaiveBayesExtractor`] for real-valued inputs (pixel intensities, sensor
+/// readings, etc). Each extracted feature is paired with the continuous value observed for it,
+/// rather than just being present or absent.
+pub trait GaussianFeatureExtractor {
+    type InputValue;
+    type FeatureId: FeatureType;
+
+    fn extract(&self, value: &Self::InputValue) -> Vec<(Self::FeatureId, f64)>;
+}
+
+/// The default [`GaussianFeatureExtractor`] used when a `NaiveBayes` is built without
+/// [`NaiveBayes::with_gaussian`]: it contributes no continuous features at all.
+pub struct NoGaussianFeatures;
+
+impl <V> GaussianFeatureExtractor for NoGaussianFeatures {
+    type InputValue = V;
+    type FeatureId = ();
+
+    fn extract(&self, _value: &V) -> Vec<((), f64)> {
+        Vec::new()
+    }
+}
+
+/// Running mean and variance for a single (label, feature) pair, updated one observation at a
+/// time via Welford's online algorithm so the full history of values never needs to be stored.
+#[derive(Clone, Copy, Default)]
+struct GaussianStats {
+    n: usize,
+    mean: f64,
+    m2: f64,
+}
+
+/// Floor applied to variance so a feature that happens to take on a single constant value for a
+/// label doesn't produce an infinite log-likelihood.
+const MIN_VARIANCE: f64 = 1e-9;
+
+impl GaussianStats {
+    fn update(&mut self, x: f64) {
+        self.n += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        if self.n < 2 {
+            MIN_VARIANCE
+        } else {
+            (self.m2 / (self.n - 1) as f64).max(MIN_VARIANCE)
+        }
+    }
+
+    fn log_likelihood(&self, x: f64) -> f64 {
+        let variance = self.variance();
+        -0.5 * (2.0 * std::f64::consts::PI * variance).ln() - (x - self.mean).powi(2) / (2.0 * variance)
+    }
+}
+
+/// Additive smoothing constant used by [`NaiveBayes::new`] when no [`NaiveBayes::with_smoothing`]
+/// override is given. `alpha = 1.0` is Laplace (add-one) smoothing.
+const DEFAULT_SMOOTHING_ALPHA: f64 = 1.0;
+
+/// Diagnostic summary produced by [`NaiveBayes::evaluate`]: a confusion matrix, overall
+/// accuracy, per-label precision/recall/F1, and percentiles of the predicted-class confidence
+/// for correct versus incorrect predictions (`None` when a bucket has no predictions in it).
+pub struct Evaluation<L: LabelType> {
+    /// `confusion_matrix[&(true_label, predicted_label)]` is how many test examples had that
+    /// combination.
+    pub confusion_matrix: BTreeMap<(L, L), usize>,
+    pub accuracy: f64,
+    pub precision: BTreeMap<L, f64>,
+    pub recall: BTreeMap<L, f64>,
+    pub f1: BTreeMap<L, f64>,
+    pub correct_confidence_p50: Option<f64>,
+    pub correct_confidence_p90: Option<f64>,
+    pub incorrect_confidence_p50: Option<f64>,
+    pub incorrect_confidence_p90: Option<f64>,
+}
+
+/// Percentile of values already sorted ascending, taken as the element at index
+/// `round(p / 100 * (len - 1))`; `p` is in `0.0..=100.0`.
+fn percentile(sorted_values: &[f64], p: f64) -> Option<f64> {
+    if sorted_values.is_empty() {
+        None
+    } else {
+        let rank = ((p / 100.0) * (sorted_values.len() - 1) as f64).round() as usize;
+        Some(sorted_values[rank])
+    }
+}
+
+pub struct NaiveBayes<L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Feature=F>, G: GaussianFeatureExtractor<InputValue=V> = NoGaussianFeatures> {
     extractor: E,
+    gaussian_extractor: G,
+    alpha: f64,
     label_counts: BTreeHistogram<L>,
-    feature_counts: HashMap<F,HashHistogram<L>>
+    feature_counts: HashMap<F,HashHistogram<L>>,
+    gaussian_stats: HashMap<(G::FeatureId, L), GaussianStats>
 }
 
-impl <L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Feature=F>> NaiveBayes<L,V,F,E> {
+impl <L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Feature=F>> NaiveBayes<L,V,F,E,NoGaussianFeatures> {
     pub fn new(extractor: E) -> Self {
-        Self { extractor, label_counts: BTreeHistogram::new(), feature_counts: HashMap::new()}
+        Self { extractor, gaussian_extractor: NoGaussianFeatures, alpha: DEFAULT_SMOOTHING_ALPHA, label_counts: BTreeHistogram::new(), feature_counts: HashMap::new(), gaussian_stats: HashMap::new() }
+    }
+
+    /// Attaches a [`GaussianFeatureExtractor`] so continuous features contribute a Gaussian
+    /// log-likelihood alongside the categorical features' multinomial one. Consumes `self`
+    /// since the swapped-in extractor changes the concrete type of the classifier.
+    pub fn with_gaussian<G: GaussianFeatureExtractor<InputValue=V>>(self, gaussian_extractor: G) -> NaiveBayes<L,V,F,E,G> {
+        NaiveBayes {
+            extractor: self.extractor,
+            gaussian_extractor,
+            alpha: self.alpha,
+            label_counts: self.label_counts,
+            feature_counts: self.feature_counts,
+            gaussian_stats: HashMap::new()
+        }
+    }
+}
+
+impl <L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Feature=F>, G: GaussianFeatureExtractor<InputValue=V>> NaiveBayes<L,V,F,E,G> {
+    /// Overrides the additive (Lidstone/Laplace) smoothing constant used in the conditional
+    /// probability `(count + alpha) / (label_total + alpha * vocabulary_size)`. `alpha < 1.0`
+    /// (Lidstone smoothing) often improves accuracy on large, sparse feature spaces; `alpha = 1.0`
+    /// (the default set by [`NaiveBayes::new`]) is Laplace smoothing.
+    pub fn with_smoothing(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
     }
 
     pub fn p_label(&self, label: &L) -> f64 {
         self.label_counts.count(label) as f64 / self.label_counts.len() as f64
     }
+
+    /// The number of distinct categorical features seen during training, i.e. the `V` term in
+    /// the smoothed conditional probability `(count + alpha) / (label_total + alpha * V)`. This
+    /// is what keeps smoothed probabilities interpretable: every vocabulary feature never seen
+    /// with a label is assigned the same small share, `alpha / (label_total + alpha * V)`, of the
+    /// probability mass. Features outside the vocabulary are skipped by [`Self::log_scores`].
+    pub fn vocabulary_size(&self) -> usize {
+        self.feature_counts.len()
+    }
+
+    /// Raw (unnormalized) log-probabilities for every label seen during training, computed
+    /// by accumulating `ln(P(label))` plus `ln(P(feature | label))` for each feature extracted
+    /// from `example`. Working in log-space avoids the underflow that multiplying many small
+    /// probabilities together would otherwise cause. Features never seen during training are
+    /// skipped and contribute nothing; `P(label)` is folded in once rather than per feature.
+    pub fn log_scores(&self, example: &V) -> BTreeMap<L, f64> {
+        let vocabulary_size = self.vocabulary_size() as f64;
+        let mut label_scores = self.label_counts.iter()
+            .map(|(label, _)| (label.clone(), self.p_label(label).ln()))
+            .collect::<BTreeMap<_,_>>();
+        for feature in self.extractor.extract(example) {
+            if let Some(fcounts) = self.feature_counts.get(&feature) {
+                for (label, label_total) in self.label_counts.iter() {
+                    let count = fcounts.count(label) as f64 + self.alpha;
+                    let label_total = *label_total as f64 + self.alpha * vocabulary_size;
+                    *label_scores.get_mut(label).unwrap() += (count / label_total).ln();
+                }
+            }
+        }
+        for (feature_id, x) in self.gaussian_extractor.extract(example) {
+            for (label, score) in label_scores.iter_mut() {
+                if let Some(stats) = self.gaussian_stats.get(&(feature_id.clone(), label.clone())) {
+                    *score += stats.log_likelihood(x);
+                }
+            }
+        }
+        label_scores
+    }
+
+    /// Normalized class probabilities: exponentiates the log-scores from [`Self::log_scores`]
+    /// and divides each by their sum, so the returned values sum to 1.0. Subtracting the
+    /// maximum log-score before exponentiating keeps this numerically stable regardless of how
+    /// negative the raw log-scores are.
+    pub fn posteriors(&self, example: &V) -> BTreeMap<L, f64> {
+        let log_scores = self.log_scores(example);
+        let max_log_score = log_scores.values().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let unnormalized = log_scores.into_iter()
+            .map(|(label, score)| (label, (score - max_log_score).exp()))
+            .collect::<BTreeMap<_,_>>();
+        let total: f64 = unnormalized.values().sum();
+        unnormalized.into_iter().map(|(label, prob)| (label, prob / total)).collect()
+    }
+
+    /// All labels ranked by posterior probability, highest first. Useful for top-k predictions
+    /// or for applying a confidence threshold via [`Self::confidence_margin`].
+    pub fn classify_ranked(&self, example: &V) -> Vec<(L, f64)> {
+        let mut ranked = self.posteriors(example).into_iter().collect::<Vec<_>>();
+        ranked.sort_by(|a, b| cmp_f64(&b.1, &a.1));
+        ranked
+    }
+
+    /// The gap between the top-ranked posterior probability and the runner-up, or the top
+    /// probability itself when only one label was seen during training. A small margin means
+    /// the classifier is torn between its top two labels for this example.
+    pub fn confidence_margin(&self, example: &V) -> f64 {
+        let ranked = self.classify_ranked(example);
+        match ranked.as_slice() {
+            [(_, top), (_, runner_up), ..] => top - runner_up,
+            [(_, top)] => *top,
+            [] => 0.0,
+        }
+    }
+
+    /// Classifies every example in `test` and reports how well the predictions matched the
+    /// given labels: a confusion matrix, overall accuracy, per-label precision/recall/F1, and
+    /// confidence percentiles split by whether the prediction was correct.
+    pub fn evaluate(&self, test: &Vec<(L,V)>) -> Evaluation<L> {
+        let mut confusion_matrix = BTreeMap::new();
+        let mut correct_confidences = Vec::new();
+        let mut incorrect_confidences = Vec::new();
+        let mut correct = 0;
+        for (true_label, example) in test.iter() {
+            let (predicted_label, confidence) = self.classify_ranked(example).remove(0);
+            *confusion_matrix.entry((true_label.clone(), predicted_label.clone())).or_insert(0) += 1;
+            if predicted_label == *true_label {
+                correct += 1;
+                correct_confidences.push(confidence);
+            } else {
+                incorrect_confidences.push(confidence);
+            }
+        }
+
+        let labels = confusion_matrix.keys()
+            .flat_map(|(true_label, predicted_label)| [true_label.clone(), predicted_label.clone()])
+            .collect::<BTreeSet<_>>();
+        let mut precision = BTreeMap::new();
+        let mut recall = BTreeMap::new();
+        let mut f1 = BTreeMap::new();
+        for label in labels {
+            let true_positive = *confusion_matrix.get(&(label.clone(), label.clone())).unwrap_or(&0) as f64;
+            let predicted_positive = confusion_matrix.iter()
+                .filter(|((_, predicted_label), _)| *predicted_label == label)
+                .map(|(_, count)| *count as f64).sum::<f64>();
+            let actual_positive = confusion_matrix.iter()
+                .filter(|((true_label, _), _)| *true_label == label)
+                .map(|(_, count)| *count as f64).sum::<f64>();
+            let p = if predicted_positive > 0.0 { true_positive / predicted_positive } else { 0.0 };
+            let r = if actual_positive > 0.0 { true_positive / actual_positive } else { 0.0 };
+            let f = if p + r > 0.0 { 2.0 * p * r / (p + r) } else { 0.0 };
+            precision.insert(label.clone(), p);
+            recall.insert(label.clone(), r);
+            f1.insert(label, f);
+        }
+
+        correct_confidences.sort_by(cmp_f64);
+        incorrect_confidences.sort_by(cmp_f64);
+
+        Evaluation {
+            accuracy: correct as f64 / test.len() as f64,
+            correct_confidence_p50: percentile(&correct_confidences, 50.0),
+            correct_confidence_p90: percentile(&correct_confidences, 90.0),
+            incorrect_confidence_p50: percentile(&incorrect_confidences, 50.0),
+            incorrect_confidence_p90: percentile(&incorrect_confidences, 90.0),
+            confusion_matrix,
+            precision,
+            recall,
+            f1,
+        }
+    }
 }
 
-impl <L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Feature=F>> Classifier<V,L> for NaiveBayes<L,V,F,E> {
+impl <L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Feature=F>, G: GaussianFeatureExtractor<InputValue=V>> Classifier<V,L> for NaiveBayes<L,V,F,E,G> {
     fn train(&mut self, training_images: &Vec<(L,V)>) {
         for (label, value) in training_images.iter() {
             self.label_counts.bump(label);
@@ -59,31 +309,137 @@ impl <L: LabelType, V, F: FeatureType, E:NaiveBayesExtractor<InputValue=V, Featu
                     self.feature_counts.insert(feature.clone(), HashHistogram::new());
                 }
                 self.feature_counts.get_mut(&feature).unwrap().bump(label);
-            }            
+            }
+            for (feature_id, x) in self.gaussian_extractor.extract(value) {
+                self.gaussian_stats.entry((feature_id, label.clone())).or_insert_with(GaussianStats::default).update(x);
+            }
         }
     }
 
     fn classify(&self, example: &V) -> L {
-        let mut label_probs = self.label_counts.iter().map(|(label,_)| (label, 1.0)).collect::<BTreeMap<_,_>>();
-        for feature in self.extractor.extract(example) {
-            for (label, label_total) in self.label_counts.iter() {
-                let label_total = *label_total + 1;
-                if let Some(fcounts) = self.feature_counts.get(&feature) {
-                    let count = fcounts.count(label) + 1;
-                    (*label_probs.get_mut(label).unwrap()) *= count as f64 / label_total as f64 * self.p_label(label);
+        self.classify_ranked(example).remove(0).0
+    }
+}
+
+/// On-disk representation of a trained [`NaiveBayes`] model: just the smoothing constant plus
+/// the label and feature counts. The extractor carries no state of its own in general, so
+/// [`NaiveBayes::load`]/[`NaiveBayes::load_json`] take a fresh one from the caller rather than
+/// trying to serialize it.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct PersistedModel<L: Ord, F: Hash + Eq> {
+    alpha: f64,
+    label_counts: BTreeMap<L, usize>,
+    feature_counts: HashMap<F, BTreeMap<L, usize>>,
+}
+
+/// Error type returned by [`NaiveBayes`]'s save/load methods, covering the I/O and
+/// (de)serialization failures those methods can hit.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum ModelError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Binary(Box<bincode::ErrorKind>),
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for ModelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModelError::Io(e) => write!(f, "I/O error: {e}"),
+            ModelError::Json(e) => write!(f, "JSON error: {e}"),
+            ModelError::Binary(e) => write!(f, "binary encoding error: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for ModelError {}
+
+#[cfg(feature = "serde")]
+impl From<std::io::Error> for ModelError {
+    fn from(e: std::io::Error) -> Self {
+        ModelError::Io(e)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for ModelError {
+    fn from(e: serde_json::Error) -> Self {
+        ModelError::Json(e)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Box<bincode::ErrorKind>> for ModelError {
+    fn from(e: Box<bincode::ErrorKind>) -> Self {
+        ModelError::Binary(e)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl <L: LabelType + Serialize + serde::de::DeserializeOwned, V, F: FeatureType + Serialize + serde::de::DeserializeOwned, E: NaiveBayesExtractor<InputValue=V, Feature=F>> NaiveBayes<L,V,F,E,NoGaussianFeatures> {
+    fn to_persisted(&self) -> PersistedModel<L, F> {
+        PersistedModel {
+            alpha: self.alpha,
+            label_counts: self.label_counts.iter().map(|(label, count)| (label.clone(), *count)).collect(),
+            feature_counts: self.feature_counts.iter()
+                .map(|(feature, counts)| (feature.clone(), counts.iter().map(|(label, count)| (label.clone(), *count)).collect()))
+                .collect(),
+        }
+    }
+
+    fn from_persisted(extractor: E, persisted: PersistedModel<L, F>) -> Self {
+        let mut model = Self::new(extractor);
+        model.alpha = persisted.alpha;
+        for (label, count) in persisted.label_counts {
+            for _ in 0..count {
+                model.label_counts.bump(&label);
+            }
+        }
+        for (feature, label_counts) in persisted.feature_counts {
+            let mut counts = HashHistogram::new();
+            for (label, count) in label_counts {
+                for _ in 0..count {
+                    counts.bump(&label);
                 }
             }
+            model.feature_counts.insert(feature, counts);
         }
+        model
+    }
 
-        let mut rankings = label_probs.iter().map(|(label, prob)| (*prob, (*label).clone())).collect::<Vec<_>>();
-        rankings.sort_by(cmp_w_label);
-        println!("{rankings:?}");
-        rankings.last().unwrap().1.clone()
+    /// Saves this model's label/feature counts and smoothing constant to `path` in a compact
+    /// binary format. Pair with [`NaiveBayes::load`] to skip retraining across runs.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), ModelError> {
+        let file = std::fs::File::create(path)?;
+        bincode::serialize_into(file, &self.to_persisted())?;
+        Ok(())
+    }
+
+    /// Loads a model previously written by [`NaiveBayes::save`]. `extractor` is not persisted
+    /// (it carries no state in general) and must be supplied fresh by the caller.
+    pub fn load(path: impl AsRef<std::path::Path>, extractor: E) -> Result<Self, ModelError> {
+        let file = std::fs::File::open(path)?;
+        let persisted = bincode::deserialize_from(file)?;
+        Ok(Self::from_persisted(extractor, persisted))
     }
-}
 
-fn cmp_w_label<L: LabelType, V: Copy + PartialEq + PartialOrd>(a: &(V, L), b: &(V, L)) -> Ordering {
-    cmp_f64(&a.0, &b.0)
+    /// Same as [`NaiveBayes::save`], but writes human-readable JSON instead of a binary format.
+    pub fn save_json(&self, path: impl AsRef<std::path::Path>) -> Result<(), ModelError> {
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, &self.to_persisted())?;
+        Ok(())
+    }
+
+    /// Same as [`NaiveBayes::load`], but reads the JSON format written by
+    /// [`NaiveBayes::save_json`].
+    pub fn load_json(path: impl AsRef<std::path::Path>, extractor: E) -> Result<Self, ModelError> {
+        let file = std::fs::File::open(path)?;
+        let persisted = serde_json::from_reader(file)?;
+        Ok(Self::from_persisted(extractor, persisted))
+    }
 }
 
 // Borrowed from: https://users.rust-lang.org/t/sorting-vector-of-vectors-of-f64/16264
@@ -115,20 +471,15 @@ mod tests {
     #[test]
     fn it_works() {
         // P(A) = 3/5, P(B) = 2/5
-        // Add 1 to numerator and denominator each time to prevent zeros
+        // 6 distinct features are seen across training, so count smoothing adds 1 to the
+        // numerator and 6 (the vocabulary size) to the denominator to prevent zeros.
         //
-        // P('A' | ('X', 5)) = P(('X', 5) | 'A') P('A') = (2/3) 3/4 * 3/5 = 9/20
-        // P('B' | ('X', 5)) = P(('X', 5) | 'B') P('B') = (1/2) 2/3 * 2/5 = 4/15
-        // P('A' | ('X', 3)) = P(('X', 3) | 'A') P('A') = (1/3) 2/4 * 3/5 = 6/20
-        // P('B' | ('X', 3)) = P(('X', 3) | 'B') P('B') = (0/2) 1/3 * 2/5 = 2/15
-        // P('A' | ('X', 4)) = P(('X', 4) | 'A') P('A') = (0/3) 1/4 * 3/5 = 3/20
-        // P('B' | ('X', 4)) = P(('X', 4) | 'B') P('B') = (1/2) 2/3 * 2/5 = 4/15
-        // P('A' | ('Y', 4)) = P(('Y', 4) | 'A') P('A') = (1/3) 2/4 * 3/5 = 6/20
-        // P('B' | ('Y', 4)) = P(('Y', 4) | 'B') P('B') = (1/2) 2/3 * 2/5 = 4/15
-        // P('A' | ('Y', 3)) = P(('Y', 3) | 'A') P('A') = (0/3) 1/4 * 3/5 = 3/20
-        // P('B' | ('Y', 3)) = P(('Y', 3) | 'B') P('B') = (1/2) 2/3 * 2/5 = 4/15
-        // P('A' | ('Y', 2)) = P(('Y', 2) | 'A') P('A') = (2/3) 3/4 * 3/5 = 9/20
-        // P('B' | ('Y', 2)) = P(('Y', 2) | 'B') P('B') = (0/2) 1/3 * 2/5 = 2/15
+        // P('A' | ('X', 5)) = (2+1)/(3+6) * 3/5, P('B' | ('X', 5)) = (1+1)/(2+6) * 2/5
+        // P('A' | ('X', 3)) = (1+1)/(3+6) * 3/5, P('B' | ('X', 3)) = (0+1)/(2+6) * 2/5
+        // P('A' | ('X', 4)) = (0+1)/(3+6) * 3/5, P('B' | ('X', 4)) = (1+1)/(2+6) * 2/5
+        // P('A' | ('Y', 4)) = (1+1)/(3+6) * 3/5, P('B' | ('Y', 4)) = (1+1)/(2+6) * 2/5
+        // P('A' | ('Y', 3)) = (0+1)/(3+6) * 3/5, P('B' | ('Y', 3)) = (1+1)/(2+6) * 2/5
+        // P('A' | ('Y', 2)) = (2+1)/(3+6) * 3/5, P('B' | ('Y', 2)) = (0+1)/(2+6) * 2/5
         let training = vec![
             ('A', vec![('X', 5), ('Y', 4)]), 
             ('A', vec![('X', 5), ('Y', 2)]), 
@@ -138,22 +489,22 @@ mod tests {
         ];
 
         let testing = vec![
-            // P('A') = 9/20 * 9/20, P('B') = 4/15 * 2/15
+            // P('A') wins on ('X', 5) and ('Y', 2)
             ('A', vec![('X', 5), ('Y', 2)]),
 
-            // P('A') = 3/20 * 9/20, P('B') = 4/15 * 2/15
+            // P('A') still edges out P('B') once ('X', 5) is replaced with the weaker ('X', 4)
             ('A', vec![('X', 4), ('Y', 2)]),
 
-            // P('A') = 3/20, P('B') = 4/15
+            // ('Y', 1) is unseen and contributes nothing, leaving P('B') ahead on ('X', 4) alone
             ('B', vec![('X', 4), ('Y', 1)]),
 
-            // P('A') = 9/20, P('B') = 4/15
+            // ('Y', 1) is unseen, leaving P('A') ahead on ('X', 5) alone
             ('A', vec![('X', 5), ('Y', 1)]),
 
-            // P('A') = 3/20, P('B') = 4/15
+            // ('X', 2) is unseen, leaving P('B') ahead on ('Y', 3) alone
             ('B', vec![('X', 2), ('Y', 3)]),
 
-            // P('A') = 6/20 * 3/20, P('B') = 2/15 * 4/15
+            // P('A') wins on ('X', 3) and ('Y', 3)
             ('A', vec![('X', 3), ('Y', 3)]),
         ];
 